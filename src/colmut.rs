@@ -0,0 +1,96 @@
+use std::marker::PhantomData;
+
+/// Iterator over the columns of a matrix, yielding one [`ColumnIterMut`] per
+/// column. Returned by `Matrix::iter_cols_mut`/`MatrixSliceMut::iter_cols_mut`.
+///
+/// Columns interleave in memory (element `(r, c)` lives `stride` elements
+/// after `(r - 1, c)`), so this cannot be built by splitting the backing
+/// slice into contiguous pieces; it walks raw pointers instead.
+pub struct ColumnsMut<'a, T> {
+    base: *mut T,
+    rows: usize,
+    cols: usize,
+    stride: usize,
+    next_col: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> ColumnsMut<'a, T> {
+    /// # Safety
+    ///
+    /// `base` must point to the first element of a `rows x cols` (logical)
+    /// region with row stride `stride` elements, and the caller must hold a
+    /// unique borrow over that entire region for lifetime `'a`.
+    pub(crate) unsafe fn new(base: *mut T, rows: usize, cols: usize, stride: usize) -> Self {
+        Self {
+            base,
+            rows,
+            cols,
+            stride,
+            next_col: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ColumnsMut<'a, T> {
+    type Item = ColumnIterMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_col >= self.cols {
+            return None;
+        }
+        // Safety: `next_col < cols`, and each column is only ever handed out
+        // once, so the `&mut T`s it yields never alias those of any other
+        // column from this iterator.
+        let ptr = unsafe { self.base.add(self.next_col) };
+        self.next_col += 1;
+        Some(ColumnIterMut {
+            ptr,
+            remaining: self.rows,
+            stride: self.stride,
+            _marker: PhantomData,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cols - self.next_col;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ColumnsMut<'a, T> {}
+
+/// Mutable iterator walking a single column, advancing `stride` elements at
+/// a time.
+pub struct ColumnIterMut<'a, T> {
+    ptr: *mut T,
+    remaining: usize,
+    stride: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for ColumnIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // Safety: within a column, successive strides never overlap, and
+        // `remaining` guarantees we stay inside the `rows x cols` region
+        // this iterator was constructed over. The pointer advanced past the
+        // last row is never dereferenced, so it is computed with
+        // `wrapping_add` rather than `add`.
+        let item = unsafe { &mut *self.ptr };
+        self.ptr = self.ptr.wrapping_add(self.stride);
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ColumnIterMut<'a, T> {}
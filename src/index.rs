@@ -0,0 +1,223 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::{matrix::Matrix, slice::MatrixSlice, slicemut::MatrixSliceMut};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for (usize, usize) {}
+    impl Sealed for usize {}
+    impl Sealed for (std::ops::Range<usize>, std::ops::Range<usize>) {}
+}
+
+/// A helper trait used to index into a [`Matrix`], in the style of
+/// [`core::slice::SliceIndex`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+/// It lets [`Matrix::get`]/[`Matrix::get_mut`] be generic over the kind of
+/// index used: a single `(row, col)` coordinate yields a single element, a
+/// plain `usize` yields a whole row, and a `(Range<usize>, Range<usize>)`
+/// yields a borrowed rectangular view of the matrix.
+///
+/// The output differs per kind (a reference, a slice, or a view struct), so
+/// it's expressed as a pair of lifetime-generic associated types rather
+/// than a single `Output: ?Sized`.
+///
+/// # Safety
+///
+/// Implementors of the `_unchecked` methods must guarantee that, provided
+/// the matrix reference is valid and `self` is in bounds for it, the
+/// returned value is valid. This mirrors the checked/unchecked split
+/// already used by [`Matrix::get_unchecked`].
+pub trait MatrixIndex<T>: private::Sealed {
+    /// The output type returned by [`get`](MatrixIndex::get).
+    type Output<'a>: 'a
+    where
+        T: 'a;
+
+    /// The output type returned by [`get_mut`](MatrixIndex::get_mut).
+    type OutputMut<'a>: 'a
+    where
+        T: 'a;
+
+    /// Returns the output at this index, or `None` if it is out of bounds
+    /// for `m`.
+    fn get(self, m: &Matrix<T>) -> Option<Self::Output<'_>>;
+
+    /// Returns the mutable output at this index, or `None` if it is out of
+    /// bounds for `m`.
+    fn get_mut(self, m: &mut Matrix<T>) -> Option<Self::OutputMut<'_>>;
+
+    /// # Safety
+    ///
+    /// This function is unsafe (just like [`slice::get_unchecked`])
+    ///
+    /// For a safe version of this function, see [`get`].
+    ///
+    /// [`slice::get_unchecked`]: slice::get_unchecked
+    /// [`get`]: MatrixIndex::get
+    unsafe fn get_unchecked(self, m: &Matrix<T>) -> Self::Output<'_>;
+
+    /// # Safety
+    ///
+    /// This function is unsafe (just like [`slice::get_unchecked_mut`])
+    ///
+    /// For a safe version of this function, see [`get_mut`].
+    ///
+    /// [`slice::get_unchecked_mut`]: slice::get_unchecked_mut
+    /// [`get_mut`]: MatrixIndex::get_mut
+    unsafe fn get_unchecked_mut(self, m: &mut Matrix<T>) -> Self::OutputMut<'_>;
+}
+
+impl<T> MatrixIndex<T> for (usize, usize) {
+    type Output<'a>
+        = &'a T
+    where
+        T: 'a;
+    type OutputMut<'a>
+        = &'a mut T
+    where
+        T: 'a;
+
+    fn get(self, m: &Matrix<T>) -> Option<&T> {
+        let (row, col) = self;
+        if row < m.rows() && col < m.cols() {
+            Some(unsafe { self.get_unchecked(m) })
+        } else {
+            None
+        }
+    }
+
+    fn get_mut(self, m: &mut Matrix<T>) -> Option<&mut T> {
+        let (row, col) = self;
+        if row < m.rows() && col < m.cols() {
+            Some(unsafe { self.get_unchecked_mut(m) })
+        } else {
+            None
+        }
+    }
+
+    unsafe fn get_unchecked(self, m: &Matrix<T>) -> &T {
+        let (row, col) = self;
+        m.get_unchecked(row, col)
+    }
+
+    unsafe fn get_unchecked_mut(self, m: &mut Matrix<T>) -> &mut T {
+        let (row, col) = self;
+        m.get_unchecked_mut(row, col)
+    }
+}
+
+impl<T> MatrixIndex<T> for usize {
+    type Output<'a>
+        = &'a [T]
+    where
+        T: 'a;
+    type OutputMut<'a>
+        = &'a mut [T]
+    where
+        T: 'a;
+
+    fn get(self, m: &Matrix<T>) -> Option<&[T]> {
+        if self < m.rows() {
+            Some(unsafe { self.get_unchecked(m) })
+        } else {
+            None
+        }
+    }
+
+    fn get_mut(self, m: &mut Matrix<T>) -> Option<&mut [T]> {
+        if self < m.rows() {
+            Some(unsafe { self.get_unchecked_mut(m) })
+        } else {
+            None
+        }
+    }
+
+    unsafe fn get_unchecked(self, m: &Matrix<T>) -> &[T] {
+        let cols = m.cols();
+        m.data().get_unchecked(self * cols..(self + 1) * cols)
+    }
+
+    unsafe fn get_unchecked_mut(self, m: &mut Matrix<T>) -> &mut [T] {
+        let cols = m.cols();
+        m.data_mut().get_unchecked_mut(self * cols..(self + 1) * cols)
+    }
+}
+
+impl<T> MatrixIndex<T> for (Range<usize>, Range<usize>) {
+    type Output<'a>
+        = MatrixSlice<'a, T>
+    where
+        T: 'a;
+    type OutputMut<'a>
+        = MatrixSliceMut<'a, T>
+    where
+        T: 'a;
+
+    fn get(self, m: &Matrix<T>) -> Option<MatrixSlice<'_, T>> {
+        let (rows, cols) = &self;
+        let in_bounds = rows.start <= rows.end && cols.start <= cols.end && rows.end <= m.rows() && cols.end <= m.cols();
+        if in_bounds {
+            Some(unsafe { self.get_unchecked(m) })
+        } else {
+            None
+        }
+    }
+
+    fn get_mut(self, m: &mut Matrix<T>) -> Option<MatrixSliceMut<'_, T>> {
+        let (rows, cols) = &self;
+        let in_bounds = rows.start <= rows.end && cols.start <= cols.end && rows.end <= m.rows() && cols.end <= m.cols();
+        if in_bounds {
+            Some(unsafe { self.get_unchecked_mut(m) })
+        } else {
+            None
+        }
+    }
+
+    // These bypass `Matrix::submatrix`/`submatrix_mut`'s own bounds
+    // assertions and recompute the raw parts directly: the trait's safety
+    // contract already guarantees `self` is in bounds here, so re-checking
+    // on every `get`/`get_mut` call would defeat the point of the
+    // checked/unchecked split the other `MatrixIndex` impls follow.
+    unsafe fn get_unchecked(self, m: &Matrix<T>) -> MatrixSlice<'_, T> {
+        let (rows, cols) = self;
+        let row_stride = m.cols();
+        let offset = rows.start * row_stride + cols.start;
+        MatrixSlice::from_raw_parts(rows.end - rows.start, cols.end - cols.start, offset, row_stride, m.data())
+    }
+
+    unsafe fn get_unchecked_mut(self, m: &mut Matrix<T>) -> MatrixSliceMut<'_, T> {
+        let (rows, cols) = self;
+        let row_stride = m.cols();
+        let offset = rows.start * row_stride + cols.start;
+        MatrixSliceMut::from_raw_parts(rows.end - rows.start, cols.end - cols.start, offset, row_stride, m.data_mut())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        self.get(index).expect("matrix index out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        self.get_mut(index).expect("matrix index out of bounds")
+    }
+}
+
+impl<T> Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    fn index(&self, index: usize) -> &[T] {
+        self.get(index).expect("matrix index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for Matrix<T> {
+    fn index_mut(&mut self, index: usize) -> &mut [T] {
+        self.get_mut(index).expect("matrix index out of bounds")
+    }
+}
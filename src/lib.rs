@@ -0,0 +1,7 @@
+pub mod colmut;
+pub mod index;
+pub mod matrix;
+pub mod num;
+pub mod ops;
+pub mod slice;
+pub mod slicemut;
@@ -1,6 +1,12 @@
-use std::{ops::{Index, IndexMut}, fmt::Display};
+use std::{cmp::Ordering, fmt::Display, ops::Range};
 
-use crate::{slice::MatrixSlice, slicemut::MatrixSliceMut};
+use crate::{
+    colmut::ColumnsMut,
+    index::MatrixIndex,
+    num::{One, Zero},
+    slice::MatrixSlice,
+    slicemut::MatrixSliceMut,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Matrix<T> {
@@ -47,6 +53,78 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Builds a matrix by calling `f(row, col)` once per cell, in row-major
+    /// order.
+    #[must_use]
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                data.push(f(row, col));
+            }
+        }
+        Self { rows, cols, data }
+    }
+
+    /// Builds the `n x n` identity matrix.
+    #[must_use]
+    pub fn identity(n: usize) -> Self
+    where
+        T: Zero + One,
+    {
+        Self::from_fn(n, n, |row, col| if row == col { T::one() } else { T::zero() })
+    }
+
+    /// Builds a matrix from its rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows don't all share the same length.
+    #[must_use]
+    pub fn from_rows<I>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        let rows: Vec<Vec<T>> = rows.into_iter().collect();
+        let num_rows = rows.len();
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "all rows must have the same length"
+        );
+        let data = rows.into_iter().flatten().collect();
+        Self {
+            rows: num_rows,
+            cols,
+            data,
+        }
+    }
+
+    /// Builds a matrix from its columns, transposing them into row-major
+    /// storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns don't all share the same length.
+    #[must_use]
+    pub fn from_cols<I>(cols: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<T>>,
+        T: Clone,
+    {
+        let cols: Vec<Vec<T>> = cols.into_iter().collect();
+        let num_cols = cols.len();
+        let rows = cols.first().map_or(0, Vec::len);
+        assert!(
+            cols.iter().all(|col| col.len() == rows),
+            "all columns must have the same length"
+        );
+        Self::from_fn(rows, num_cols, |row, col| cols[col][row].clone())
+    }
+
     /// # Safety
     ///
     /// This function is unsafe (just like [`slice::get_unchecked`])
@@ -54,7 +132,7 @@ impl<T> Matrix<T> {
     /// For a safe version of this function, see [`get`].
     ///
     /// [`slice::get_unchecked`]: slice::get_unchecked
-    /// [`get`]: #method.get
+    /// [`get`]: Matrix::get
     #[must_use]
     pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
         self.data.get_unchecked(row * self.cols + col)
@@ -67,26 +145,23 @@ impl<T> Matrix<T> {
     /// For a safe version of this function, see [`get_mut`].
     ///
     /// [`slice::get_unchecked_mut`]: slice::get_unchecked_mut
-    /// [`get_mut`]: #method.get_mut
+    /// [`get_mut`]: Matrix::get_mut
     pub unsafe fn get_unchecked_mut(&mut self, row: usize, col: usize) -> &mut T {
         self.data.get_unchecked_mut(row * self.cols + col)
     }
 
-    #[must_use]
-    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
-        if row < self.rows && col < self.cols {
-            Some(unsafe { self.get_unchecked(row, col) })
-        } else {
-            None
-        }
+    /// Returns the element, row, or submatrix named by `index`, or `None` if
+    /// it is out of bounds. See [`MatrixIndex`] for the index kinds this
+    /// accepts: `(usize, usize)` for a single element, `usize` for a whole
+    /// row, or `(Range<usize>, Range<usize>)` for a borrowed rectangular
+    /// view.
+    pub fn get<I: MatrixIndex<T>>(&self, index: I) -> Option<I::Output<'_>> {
+        index.get(self)
     }
 
-    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
-        if row < self.rows && col < self.cols {
-            Some(unsafe { self.get_unchecked_mut(row, col) })
-        } else {
-            None
-        }
+    /// Mutable counterpart of [`get`](Matrix::get).
+    pub fn get_mut<I: MatrixIndex<T>>(&mut self, index: I) -> Option<I::OutputMut<'_>> {
+        index.get_mut(self)
     }
 
     #[must_use]
@@ -113,12 +188,13 @@ impl<T> Matrix<T> {
         )
     }
 
-    /// # Panics
-    /// 
-    /// We haven't done this one yet. :)
-    #[allow(clippy::unused_self)]
-    pub fn iter_cols_mut(&mut self) /* -> impl Iterator<Item = impl Iterator<Item = &mut T>> */ {
-        todo!();
+    pub fn iter_cols_mut(&mut self) -> ColumnsMut<'_, T> {
+        let rows = self.rows;
+        let cols = self.cols;
+        // Safety: `data` has exactly `rows * cols` elements laid out with
+        // row stride `cols`, and `&mut self` gives us unique access to all
+        // of them for the lifetime of the returned iterator.
+        unsafe { ColumnsMut::new(self.data.as_mut_ptr(), rows, cols, cols) }
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &T> {
@@ -164,27 +240,160 @@ impl<T> Matrix<T> {
     }
 
     #[must_use]
-    pub fn as_slice(&self) -> MatrixSlice<T> {
+    pub fn as_slice(&self) -> MatrixSlice<'_, T> {
         MatrixSlice::new(self)
     }
 
     #[must_use]
-    pub fn as_slice_mut(&mut self) -> MatrixSliceMut<T> {
+    pub fn as_slice_mut(&mut self) -> MatrixSliceMut<'_, T> {
         MatrixSliceMut::new(self)
     }
-}
 
-impl<T> Index<(usize, usize)> for Matrix<T> {
-    type Output = T;
+    /// Returns a zero-copy view over the rectangular region spanning `rows`
+    /// and `cols`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is reversed (`start > end`) or either range's
+    /// end is out of bounds for this matrix.
+    #[must_use]
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixSlice<'_, T> {
+        assert!(rows.start <= rows.end && cols.start <= cols.end);
+        assert!(rows.end <= self.rows && cols.end <= self.cols);
+        let offset = rows.start * self.cols + cols.start;
+        MatrixSlice::from_raw_parts(
+            rows.end - rows.start,
+            cols.end - cols.start,
+            offset,
+            self.cols,
+            &self.data,
+        )
+    }
 
-    fn index(&self, (row, col): (usize, usize)) -> &T {
-        &self.data[row * self.cols + col]
+    /// Mutable counterpart of [`submatrix`](Matrix::submatrix).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is reversed (`start > end`) or either range's
+    /// end is out of bounds for this matrix.
+    #[must_use]
+    pub fn submatrix_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixSliceMut<'_, T> {
+        assert!(rows.start <= rows.end && cols.start <= cols.end);
+        assert!(rows.end <= self.rows && cols.end <= self.cols);
+        let offset = rows.start * self.cols + cols.start;
+        let row_stride = self.cols;
+        MatrixSliceMut::from_raw_parts(
+            rows.end - rows.start,
+            cols.end - cols.start,
+            offset,
+            row_stride,
+            &mut self.data,
+        )
+    }
+
+    /// Reorders the rows of this matrix in place according to `compare`.
+    pub fn sort_rows_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&[T], &[T]) -> Ordering,
+    {
+        let cols = self.cols;
+        let mut order: Vec<usize> = (0..self.rows).collect();
+        order.sort_unstable_by(|&a, &b| {
+            compare(
+                &self.data[a * cols..(a + 1) * cols],
+                &self.data[b * cols..(b + 1) * cols],
+            )
+        });
+        apply_chunked_permutation(&mut self.data, &order, cols);
+    }
+
+    /// Reorders the rows of this matrix in place by a key extracted from
+    /// each row.
+    pub fn sort_rows_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&[T]) -> K,
+        K: Ord,
+    {
+        self.sort_rows_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Reorders the columns of this matrix in place according to `compare`,
+    /// which is given the indices of the two columns being compared (see
+    /// [`iter_col`](Matrix::iter_col) to inspect their values).
+    pub fn sort_cols_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(usize, usize) -> Ordering,
+    {
+        let mut order: Vec<usize> = (0..self.cols).collect();
+        order.sort_unstable_by(|&a, &b| compare(a, b));
+        self.permute_cols(&order);
+    }
+
+    /// Reorders the columns of this matrix in place by a key extracted from
+    /// each column's index.
+    pub fn sort_cols_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(usize) -> K,
+        K: Ord,
+    {
+        self.sort_cols_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Applies a column permutation to every row independently, reusing the
+    /// same cycle-following machinery as [`sort_rows_by`](Matrix::sort_rows_by).
+    fn permute_cols(&mut self, order: &[usize]) {
+        let cols = self.cols;
+        for row in 0..self.rows {
+            let start = row * cols;
+            apply_chunked_permutation(&mut self.data[start..start + cols], order, 1);
+        }
     }
 }
 
-impl<T> IndexMut<(usize, usize)> for Matrix<T> {
-    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
-        &mut self.data[row * self.cols + col]
+/// Permutes `data`, understood as a flat sequence of fixed-size `chunk`-item
+/// elements, so that the item at index `i` becomes what was originally at
+/// `order[i]`. Follows each cycle of the permutation through a single
+/// `chunk`-sized temporary buffer, so no second full buffer is ever
+/// allocated.
+fn apply_chunked_permutation<T>(data: &mut [T], order: &[usize], chunk: usize) {
+    let len = order.len();
+    let mut visited = vec![false; len];
+    let base = data.as_mut_ptr();
+    for start in 0..len {
+        if visited[start] || order[start] == start {
+            visited[start] = true;
+            continue;
+        }
+        let mut temp: Vec<T> = Vec::with_capacity(chunk);
+        // Safety: `start * chunk + chunk <= data.len()` since `start < len`
+        // and `data` holds exactly `len * chunk` elements; `temp` has room
+        // for exactly `chunk` elements.
+        unsafe {
+            std::ptr::copy_nonoverlapping(base.add(start * chunk), temp.as_mut_ptr(), chunk);
+            temp.set_len(chunk);
+        }
+        visited[start] = true;
+        let mut cur = start;
+        loop {
+            let src = order[cur];
+            if src == start {
+                // Safety: as above; `temp`'s elements are logically moved
+                // out immediately after, via `set_len(0)`, so they are
+                // never dropped twice.
+                unsafe {
+                    std::ptr::copy_nonoverlapping(temp.as_ptr(), base.add(cur * chunk), chunk);
+                    temp.set_len(0);
+                }
+                break;
+            }
+            // Safety: `src` and `cur` are distinct row/column indices, so
+            // their `chunk`-sized regions never overlap.
+            unsafe {
+                std::ptr::copy_nonoverlapping(base.add(src * chunk), base.add(cur * chunk), chunk);
+            }
+            visited[src] = true;
+            cur = src;
+        }
     }
 }
 
@@ -241,18 +450,20 @@ macro_rules! matrix {
 
 #[cfg(test)]
 mod tests {
+    use super::Matrix;
+
     #[test]
     fn macro_simple() {
         let m = matrix![1, 2, 3; 4, 5, 6];
         assert_eq!(m.rows(), 2);
         assert_eq!(m.cols(), 3);
-        assert_eq!(m.get(0, 0), Some(&1));
-        assert_eq!(m.get(0, 1), Some(&2));
-        assert_eq!(m.get(0, 2), Some(&3));
-        assert_eq!(m.get(1, 0), Some(&4));
-        assert_eq!(m.get(1, 1), Some(&5));
-        assert_eq!(m.get(1, 2), Some(&6));
-        assert_eq!(m.get(2, 0), None);
+        assert_eq!(m.get((0, 0)), Some(&1));
+        assert_eq!(m.get((0, 1)), Some(&2));
+        assert_eq!(m.get((0, 2)), Some(&3));
+        assert_eq!(m.get((1, 0)), Some(&4));
+        assert_eq!(m.get((1, 1)), Some(&5));
+        assert_eq!(m.get((1, 2)), Some(&6));
+        assert_eq!(m.get((2, 0)), None);
     }
 
     #[test]
@@ -318,4 +529,186 @@ mod tests {
  [0, 0, 3]]
 "#);
     }
+
+    #[test]
+    fn row_indexing() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6
+        ];
+        assert_eq!(&m[0], &[1, 2, 3]);
+        assert_eq!(&m[1], &[4, 5, 6]);
+        assert_eq!(m.get(2), None);
+    }
+
+    #[test]
+    fn from_fn_fills_row_major() {
+        let m = Matrix::from_fn(2, 3, |r, c| r * 3 + c);
+        assert_eq!(m.clone_buffer(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn identity_matrix() {
+        let m = Matrix::<i32>::identity(3);
+        assert_eq!(m.get((0, 0)), Some(&1));
+        assert_eq!(m.get((1, 1)), Some(&1));
+        assert_eq!(m.get((2, 2)), Some(&1));
+        assert_eq!(m.get((0, 1)), Some(&0));
+        assert_eq!(m.get((2, 0)), Some(&0));
+    }
+
+    #[test]
+    fn from_rows_and_cols() {
+        let from_rows = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let from_cols = Matrix::from_cols(vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(from_rows, from_cols);
+        assert_eq!(from_rows.rows(), 2);
+        assert_eq!(from_rows.cols(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "all rows must have the same length")]
+    fn from_rows_rejects_ragged_input() {
+        let _ = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn sort_rows_by_key_column() {
+        let mut m = matrix![
+            3, 0;
+            1, 0;
+            2, 0
+        ];
+        m.sort_rows_by_key(|row| row[0]);
+        assert_eq!(m.clone_buffer(), vec![1, 0, 2, 0, 3, 0]);
+    }
+
+    #[test]
+    fn sort_cols_by_key_first_row() {
+        let mut m = matrix![
+            3, 1, 2;
+            6, 4, 5
+        ];
+        let first_row: Vec<_> = m.iter_row(0).copied().collect();
+        m.sort_cols_by_key(|col| first_row[col]);
+        assert_eq!(m.clone_buffer(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn col_iteration_mut() {
+        let mut m = matrix![
+            1, 2, 3;
+            4, 5, 6
+        ];
+        for col in m.iter_cols_mut() {
+            for v in col {
+                *v *= 10;
+            }
+        }
+        let vals = m.iter().copied().collect::<Vec<i32>>();
+        assert_eq!(vals, &[10, 20, 30, 40, 50, 60]);
+    }
+
+    #[test]
+    fn submatrix_view() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        ];
+        let sub = m.submatrix(1..3, 1..3);
+        assert_eq!(sub.rows(), 2);
+        assert_eq!(sub.cols(), 2);
+        assert_eq!(sub.get(0, 0), Some(&5));
+        assert_eq!(sub.get(0, 1), Some(&6));
+        assert_eq!(sub.get(1, 0), Some(&8));
+        assert_eq!(sub.get(1, 1), Some(&9));
+        let rows: Vec<_> = sub.iter_rows().map(<[i32]>::to_vec).collect();
+        assert_eq!(rows, vec![vec![5, 6], vec![8, 9]]);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn submatrix_rejects_reversed_row_range() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        ];
+        let _ = m.submatrix(3..1, 0..2);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn submatrix_mut_rejects_reversed_col_range() {
+        let mut m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        ];
+        let _ = m.submatrix_mut(0..2, 3..1);
+    }
+
+    #[test]
+    fn submatrix_view_mut() {
+        let mut m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        ];
+        {
+            let mut sub = m.submatrix_mut(0..2, 0..2);
+            for v in sub.iter_mut() {
+                *v *= 10;
+            }
+        }
+        assert_eq!(m.get((0, 0)), Some(&10));
+        assert_eq!(m.get((0, 1)), Some(&20));
+        assert_eq!(m.get((1, 0)), Some(&40));
+        assert_eq!(m.get((1, 1)), Some(&50));
+        assert_eq!(m.get((0, 2)), Some(&3));
+    }
+
+    #[test]
+    fn submatrix_mut_iter_cols_mut_respects_stride() {
+        let mut m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        ];
+        {
+            let mut sub = m.submatrix_mut(0..2, 1..3);
+            for col in sub.iter_cols_mut() {
+                for v in col {
+                    *v *= 10;
+                }
+            }
+        }
+        assert_eq!(m.clone_buffer(), vec![1, 20, 30, 4, 50, 60, 7, 8, 9]);
+    }
+
+    #[test]
+    fn get_with_range_pair_yields_submatrix_view() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9
+        ];
+        let sub = m.get((0..2, 1..3)).unwrap();
+        assert_eq!(sub.rows(), 2);
+        assert_eq!(sub.cols(), 2);
+        assert_eq!(sub.get(0, 0), Some(&2));
+        assert_eq!(sub.get(1, 1), Some(&6));
+        assert!(m.get((0..4, 0..1)).is_none());
+    }
+
+    #[test]
+    fn element_indexing() {
+        let mut m = matrix![1, 2; 3, 4];
+        assert_eq!(m[(1, 0)], 3);
+        m[(1, 0)] = 9;
+        assert_eq!(m[(1, 0)], 9);
+    }
 }
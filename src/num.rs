@@ -0,0 +1,46 @@
+//! Minimal `Zero`/`One` traits used by [`crate::matrix::Matrix::identity`]
+//! and the matrix product in [`crate::ops`], so the crate doesn't need to
+//! depend on an external numeric-traits crate.
+
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+pub trait One {
+    fn one() -> Self;
+}
+
+macro_rules! impl_zero_one {
+    ($($t:ty => $zero:expr, $one:expr);* $(;)?) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_one! {
+    i8 => 0, 1;
+    i16 => 0, 1;
+    i32 => 0, 1;
+    i64 => 0, 1;
+    i128 => 0, 1;
+    isize => 0, 1;
+    u8 => 0, 1;
+    u16 => 0, 1;
+    u32 => 0, 1;
+    u64 => 0, 1;
+    u128 => 0, 1;
+    usize => 0, 1;
+    f32 => 0.0, 1.0;
+    f64 => 0.0, 1.0;
+}
@@ -0,0 +1,160 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::{matrix::Matrix, num::Zero};
+
+impl<T> Add for Matrix<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` don't have the same dimensions.
+    fn add(self, rhs: Matrix<T>) -> Matrix<T> {
+        assert!(
+            self.rows() == rhs.rows() && self.cols() == rhs.cols(),
+            "cannot add a {}x{} matrix to a {}x{} matrix",
+            self.rows(),
+            self.cols(),
+            rhs.rows(),
+            rhs.cols()
+        );
+        Matrix::from_fn(self.rows(), self.cols(), |r, c| self[(r, c)] + rhs[(r, c)])
+    }
+}
+
+impl<T> Sub for Matrix<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` don't have the same dimensions.
+    fn sub(self, rhs: Matrix<T>) -> Matrix<T> {
+        assert!(
+            self.rows() == rhs.rows() && self.cols() == rhs.cols(),
+            "cannot subtract a {}x{} matrix from a {}x{} matrix",
+            rhs.rows(),
+            rhs.cols(),
+            self.rows(),
+            self.cols()
+        );
+        Matrix::from_fn(self.rows(), self.cols(), |r, c| self[(r, c)] - rhs[(r, c)])
+    }
+}
+
+impl<T> Mul<T> for Matrix<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        Matrix::from_fn(self.rows(), self.cols(), |r, c| self[(r, c)] * scalar)
+    }
+}
+
+impl<T> Mul<Matrix<T>> for Matrix<T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// # Panics
+    ///
+    /// Panics if `self`'s column count doesn't match `rhs`'s row count.
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            self.cols(),
+            rhs.rows(),
+            "cannot multiply a {}x{} matrix by a {}x{} matrix",
+            self.rows(),
+            self.cols(),
+            rhs.rows(),
+            rhs.cols()
+        );
+        let mut out = Matrix::from_default(self.rows(), rhs.cols(), T::zero());
+        // i-k-j order: stream one scalar `self[(i, k)]` against the
+        // contiguous row `k` of `rhs`, so the inner loop is a
+        // contiguous-stride fused multiply-add over `out`'s row, rather
+        // than the strided dot product the naive i-j-k order would do.
+        for i in 0..self.rows() {
+            for k in 0..self.cols() {
+                let a_ik = self[(i, k)];
+                for j in 0..rhs.cols() {
+                    out[(i, j)] = out[(i, j)] + a_ik * rhs[(k, j)];
+                }
+            }
+        }
+        out
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy,
+{
+    /// Returns the transpose of this matrix, as a new out-of-place copy.
+    #[must_use]
+    pub fn transpose(&self) -> Matrix<T> {
+        Matrix::from_fn(self.cols(), self.rows(), |r, c| self[(c, r)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn add_element_wise() {
+        let a = crate::matrix![1, 2; 3, 4];
+        let b = crate::matrix![5, 6; 7, 8];
+        assert_eq!(a + b, crate::matrix![6, 8; 10, 12]);
+    }
+
+    #[test]
+    fn sub_element_wise() {
+        let a = crate::matrix![5, 6; 7, 8];
+        let b = crate::matrix![1, 2; 3, 4];
+        assert_eq!(a - b, crate::matrix![4, 4; 4, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a 2x2 matrix to a 1x2 matrix")]
+    fn add_panics_on_mismatched_dimensions() {
+        let a = crate::matrix![1, 2; 3, 4];
+        let b = crate::matrix![1, 2];
+        let _ = a + b;
+    }
+
+    #[test]
+    fn scalar_mul() {
+        let a = crate::matrix![1, 2; 3, 4];
+        assert_eq!(a * 2, crate::matrix![2, 4; 6, 8]);
+    }
+
+    #[test]
+    fn matrix_mul() {
+        let a = crate::matrix![1, 2; 3, 4];
+        let b = crate::matrix![5, 6; 7, 8];
+        assert_eq!(a * b, crate::matrix![19, 22; 43, 50]);
+    }
+
+    #[test]
+    fn matrix_mul_non_square() {
+        let a = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::from_rows(vec![vec![7, 8], vec![9, 10], vec![11, 12]]);
+        assert_eq!(a * b, crate::matrix![58, 64; 139, 154]);
+    }
+
+    #[test]
+    fn transpose() {
+        let a = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(a.transpose(), Matrix::from_cols(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+        assert_eq!(a.transpose().rows(), 3);
+        assert_eq!(a.transpose().cols(), 2);
+    }
+}
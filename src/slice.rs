@@ -4,6 +4,8 @@ use crate::matrix::Matrix;
 pub struct MatrixSlice<'a, T> {
     rows: usize,
     cols: usize,
+    offset: usize,
+    row_stride: usize,
     data: &'a [T],
 }
 
@@ -13,10 +15,31 @@ impl<'a, T> MatrixSlice<'a, T> {
         Self {
             rows: matrix.rows(),
             cols: matrix.cols(),
+            offset: 0,
+            row_stride: matrix.cols(),
             data: matrix.data(),
         }
     }
 
+    /// Builds a view over an arbitrary rectangular region of `data`, where
+    /// `row_stride` is the column count of the matrix `data` was borrowed
+    /// from (which may be larger than `cols` for a sub-region view).
+    pub(crate) fn from_raw_parts(
+        rows: usize,
+        cols: usize,
+        offset: usize,
+        row_stride: usize,
+        data: &'a [T],
+    ) -> Self {
+        Self {
+            rows,
+            cols,
+            offset,
+            row_stride,
+            data,
+        }
+    }
+
     /// # Safety
     ///
     /// This function is unsafe (just like [`slice::get_unchecked`])
@@ -27,7 +50,7 @@ impl<'a, T> MatrixSlice<'a, T> {
     /// [`get`]: #method.get
     #[must_use]
     pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
-        self.data.get_unchecked(row * self.cols + col)
+        self.data.get_unchecked(self.offset + row * self.row_stride + col)
     }
 
     #[must_use]
@@ -49,8 +72,13 @@ impl<'a, T> MatrixSlice<'a, T> {
         self.cols
     }
 
+    fn row_slice(&self, row: usize) -> &[T] {
+        let start = self.offset + row * self.row_stride;
+        &self.data[start..start + self.cols]
+    }
+
     pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
-        self.data.chunks(self.cols)
+        (0..self.rows).map(move |row| self.row_slice(row))
     }
 
     pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
@@ -60,15 +88,15 @@ impl<'a, T> MatrixSlice<'a, T> {
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.data.iter()
+        self.iter_rows().flatten()
     }
 
     pub fn iter_row(&self, row: usize) -> impl Iterator<Item = &T> {
-        self.data[row * self.cols..(row + 1) * self.cols].iter()
+        self.row_slice(row).iter()
     }
 
     pub fn iter_col(&self, col: usize) -> impl Iterator<Item = &T> {
-        self.data.iter().skip(col).step_by(self.cols)
+        self.data[self.offset + col..].iter().step_by(self.row_stride).take(self.rows)
     }
 
     #[must_use]
@@ -76,11 +104,6 @@ impl<'a, T> MatrixSlice<'a, T> {
     where
         T: Clone,
     {
-        self.data.to_owned()
-    }
-    
-    #[must_use]
-    pub const fn data(&self) -> &[T] {
-        self.data
+        self.iter().cloned().collect()
     }
-}
\ No newline at end of file
+}
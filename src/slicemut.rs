@@ -1,21 +1,45 @@
-use crate::matrix::Matrix;
+use crate::{colmut::ColumnsMut, matrix::Matrix};
 
 pub struct MatrixSliceMut<'a, T> {
     rows: usize,
     cols: usize,
+    offset: usize,
+    row_stride: usize,
     data: &'a mut [T],
 }
 
 impl<'a, T> MatrixSliceMut<'a, T> {
     #[must_use]
     pub fn new(matrix: &'a mut Matrix<T>) -> Self {
+        let row_stride = matrix.cols();
         Self {
             rows: matrix.rows(),
             cols: matrix.cols(),
+            offset: 0,
+            row_stride,
             data: matrix.data_mut(),
         }
     }
 
+    /// Builds a view over an arbitrary rectangular region of `data`, where
+    /// `row_stride` is the column count of the matrix `data` was borrowed
+    /// from (which may be larger than `cols` for a sub-region view).
+    pub(crate) fn from_raw_parts(
+        rows: usize,
+        cols: usize,
+        offset: usize,
+        row_stride: usize,
+        data: &'a mut [T],
+    ) -> Self {
+        Self {
+            rows,
+            cols,
+            offset,
+            row_stride,
+            data,
+        }
+    }
+
     /// # Safety
     ///
     /// This function is unsafe (just like [`slice::get_unchecked`])
@@ -26,7 +50,7 @@ impl<'a, T> MatrixSliceMut<'a, T> {
     /// [`get`]: #method.get
     #[must_use]
     pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
-        self.data.get_unchecked(row * self.cols + col)
+        self.data.get_unchecked(self.offset + row * self.row_stride + col)
     }
 
     /// # Safety
@@ -38,7 +62,7 @@ impl<'a, T> MatrixSliceMut<'a, T> {
     /// [`slice::get_unchecked_mut`]: slice::get_unchecked_mut
     /// [`get_mut`]: #method.get_mut
     pub unsafe fn get_unchecked_mut(&mut self, row: usize, col: usize) -> &mut T {
-        self.data.get_unchecked_mut(row * self.cols + col)
+        self.data.get_unchecked_mut(self.offset + row * self.row_stride + col)
     }
 
     #[must_use]
@@ -68,12 +92,21 @@ impl<'a, T> MatrixSliceMut<'a, T> {
         self.cols
     }
 
+    fn row_slice(&self, row: usize) -> &[T] {
+        let start = self.offset + row * self.row_stride;
+        &self.data[start..start + self.cols]
+    }
+
     pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
-        self.data.chunks(self.cols)
+        (0..self.rows).map(move |row| self.row_slice(row))
     }
 
     pub fn iter_rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
-        self.data.chunks_mut(self.cols)
+        let cols = self.cols;
+        self.data[self.offset..]
+            .chunks_mut(self.row_stride)
+            .take(self.rows)
+            .map(move |chunk| &mut chunk[..cols])
     }
 
     pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
@@ -82,36 +115,40 @@ impl<'a, T> MatrixSliceMut<'a, T> {
         )
     }
 
-    /// # Panics
-    /// 
-    /// We haven't done this one yet. :)
-    #[allow(clippy::unused_self)]
-    pub fn iter_cols_mut(&mut self) /* -> impl Iterator<Item = impl Iterator<Item = &mut T>> */ {
-        todo!();
+    pub fn iter_cols_mut(&mut self) -> ColumnsMut<'_, T> {
+        let rows = self.rows;
+        let cols = self.cols;
+        let stride = self.row_stride;
+        // Safety: `offset + rows * row_stride` (minus padding) stays within
+        // `data`, and `&mut self` gives us unique access to the whole view
+        // for the lifetime of the returned iterator.
+        let base = unsafe { self.data.as_mut_ptr().add(self.offset) };
+        unsafe { ColumnsMut::new(base, rows, cols, stride) }
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.data.iter()
+        self.iter_rows().flatten()
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
-        self.data.iter_mut()
+        self.iter_rows_mut().flatten()
     }
 
     pub fn iter_row(&self, row: usize) -> impl Iterator<Item = &T> {
-        self.data[row * self.cols..(row + 1) * self.cols].iter()
+        self.row_slice(row).iter()
     }
 
     pub fn iter_row_mut(&mut self, row: usize) -> impl Iterator<Item = &mut T> {
-        self.data[row * self.cols..(row + 1) * self.cols].iter_mut()
+        let start = self.offset + row * self.row_stride;
+        self.data[start..start + self.cols].iter_mut()
     }
 
     pub fn iter_col(&self, col: usize) -> impl Iterator<Item = &T> {
-        self.data.iter().skip(col).step_by(self.cols)
+        self.data[self.offset + col..].iter().step_by(self.row_stride).take(self.rows)
     }
 
     pub fn iter_col_mut(&mut self, col: usize) -> impl Iterator<Item = &mut T> {
-        self.data.iter_mut().skip(col).step_by(self.cols)
+        self.data[self.offset + col..].iter_mut().step_by(self.row_stride).take(self.rows)
     }
 
     #[must_use]
@@ -119,16 +156,6 @@ impl<'a, T> MatrixSliceMut<'a, T> {
     where
         T: Clone,
     {
-        self.data.to_owned()
+        self.iter().cloned().collect()
     }
-    
-    #[must_use]
-    pub const fn data(&self) -> &[T] {
-        self.data
-    }
-
-    #[must_use]
-    pub fn data_mut(&mut self) -> &mut [T] {
-        self.data
-    }
-}
\ No newline at end of file
+}